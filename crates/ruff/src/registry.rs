@@ -0,0 +1,3 @@
+mod rule_fix_meta;
+
+pub use rule_fix_meta::RuleFixMeta;
@@ -0,0 +1,65 @@
+//! Per-rule fix-capability classification.
+//!
+//! Replaces the boolean flag `Rule::autofixable()` used to expose (fixable
+//! or not) with a typed fix-kind, so callers -- the README generator,
+//! `--format json`, and the per-rule doc pages -- can tell readers whether
+//! applying `--fix` is always safe, conditional, or requires
+//! `--unsafe-fixes`.
+
+use serde::Serialize;
+
+use super::Rule;
+
+/// How confidently `--fix` can apply a rule's automatic fix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleFixMeta {
+    /// No automatic fix exists for this rule.
+    None,
+    /// A fix exists, but is only applied when further conditions hold (e.g.
+    /// no surrounding comments, an unambiguous single edit).
+    Conditional,
+    /// The fix is always safe to apply and never changes program behavior.
+    SafeFix,
+    /// The fix may change program behavior; only applied with
+    /// `--fix --unsafe-fixes`.
+    UnsafeFix,
+}
+
+/// Rules whose fix safety has been reviewed and classified more precisely
+/// than the conservative default below. Populate this as each rule's fix is
+/// audited; until then, any rule with a fix defaults to `Conditional` so
+/// `--fix` output is never mistaken for something it hasn't earned.
+const OVERRIDES: &[(Rule, RuleFixMeta)] = &[];
+
+impl Rule {
+    /// The fix-capability classification for this rule.
+    pub fn fix_meta(&self) -> RuleFixMeta {
+        if let Some((_, meta)) = OVERRIDES.iter().find(|(rule, _)| rule == self) {
+            return *meta;
+        }
+
+        match self.autofixable() {
+            None => RuleFixMeta::None,
+            Some(_) => RuleFixMeta::Conditional,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::{Rule, RuleFixMeta};
+
+    #[test]
+    fn none_fix_meta_agrees_with_autofixable() {
+        for rule in Rule::iter() {
+            assert_eq!(
+                rule.fix_meta() == RuleFixMeta::None,
+                rule.autofixable().is_none(),
+                "{rule:?}: fix_meta()/autofixable() disagree on whether a fix exists"
+            );
+        }
+    }
+}
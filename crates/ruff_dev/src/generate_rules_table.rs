@@ -1,13 +1,20 @@
 //! Generate a Markdown-compatible table of supported lint rules.
 #![allow(clippy::print_stdout, clippy::print_stderr)]
 
+use std::path::PathBuf;
+
 use anyhow::Result;
 use itertools::Itertools;
-use ruff::registry::{Linter, Rule, RuleNamespace, UpstreamCategory};
+use ruff::registry::{Linter, Rule, RuleFixMeta, RuleNamespace, UpstreamCategory};
 use strum::IntoEnumIterator;
 
 use crate::utils::replace_readme_section;
 
+mod completions;
+mod doc_page;
+mod json;
+mod produces;
+
 const TABLE_BEGIN_PRAGMA: &str = "<!-- Begin auto-generated sections. -->\n";
 const TABLE_END_PRAGMA: &str = "<!-- End auto-generated sections. -->";
 
@@ -16,11 +23,53 @@ const TOC_END_PRAGMA: &str = "<!-- End auto-generated table of contents. -->";
 
 const URL_PREFIX: &str = "https://beta.ruff.rs/docs/rules";
 
+const FIX_LEGEND: &str = "\
+## Fix legend
+
+The `Fix` column indicates whether `--fix` can apply a rule's fix, and whether doing so is safe:
+
+| Symbol | Meaning |
+| ------ | ------- |
+| (none) | No fix available. |
+| 🔧 | Fix available, but only applied under certain conditions. |
+| 🛠 | Safe fix. Always applied with `--fix`. |
+| ⚠️ | Unsafe fix. Only applied with `--fix --unsafe-fixes`. |
+
+Fix safety is opt-in: a rule only shows 🛠 or ⚠️ once its fix has been
+reviewed and classified in `Rule::fix_meta()`'s override table. Until
+then, any rule with a fix shows 🔧, so a blank review queue never reads
+as \"this fix is safe\".
+
+";
+
+/// Output format for `cargo dev generate-rules-table`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum Format {
+    /// Update the README table and table of contents (the default).
+    #[default]
+    Readme,
+    /// Print the full rule catalog as stable JSON, rather than touching the
+    /// README.
+    Json,
+}
+
 #[derive(clap::Args)]
 pub struct Args {
     /// Write the generated table to stdout (rather than to `README.md`).
     #[arg(long)]
     pub(crate) dry_run: bool,
+    /// Also write a standalone Markdown page per rule to this directory
+    /// (e.g. `docs/rules`). Skipped when `--dry-run` is set, same as the
+    /// README.
+    #[arg(long)]
+    pub(crate) docs_dir: Option<PathBuf>,
+    /// Output format.
+    #[arg(long, default_value = "readme")]
+    pub(crate) format: Format,
+    /// Also write a static Rust table of rule descriptors (for editor
+    /// autocompletion) to this path.
+    #[arg(long)]
+    pub(crate) completions_out: Option<PathBuf>,
 }
 
 fn generate_table(table_out: &mut String, rules: impl IntoIterator<Item = Rule>, linter: &Linter) {
@@ -29,9 +78,11 @@ fn generate_table(table_out: &mut String, rules: impl IntoIterator<Item = Rule>,
     table_out.push_str("| ---- | ---- | ------- | --- |");
     table_out.push('\n');
     for rule in rules {
-        let fix_token = match rule.autofixable() {
-            None => "",
-            Some(_) => "🛠",
+        let fix_token = match rule.fix_meta() {
+            RuleFixMeta::None => "",
+            RuleFixMeta::Conditional => "🔧",
+            RuleFixMeta::SafeFix => "🛠",
+            RuleFixMeta::UnsafeFix => "⚠️",
         };
 
         let rule_name = rule.as_ref();
@@ -54,8 +105,18 @@ fn generate_table(table_out: &mut String, rules: impl IntoIterator<Item = Rule>,
 }
 
 pub fn main(args: &Args) -> Result<()> {
+    if args.format == Format::Json {
+        println!("{}", json::generate_json()?);
+        return Ok(());
+    }
+
+    if let Some(completions_out) = &args.completions_out {
+        completions::generate_completions(completions_out)?;
+    }
+
     // Generate the table string.
     let mut table_out = String::new();
+    table_out.push_str(FIX_LEGEND);
     let mut toc_out = String::new();
     for linter in Linter::iter() {
         let codes_csv: String = match linter.common_prefix() {
@@ -113,9 +174,21 @@ pub fn main(args: &Args) -> Result<()> {
                 table_out.push('\n');
                 table_out.push('\n');
                 generate_table(&mut table_out, prefix, &linter);
+
+                if let Some(docs_dir) = &args.docs_dir {
+                    if !args.dry_run {
+                        doc_page::generate_rule_docs(docs_dir, prefix, &linter)?;
+                    }
+                }
             }
         } else {
             generate_table(&mut table_out, &linter, &linter);
+
+            if let Some(docs_dir) = &args.docs_dir {
+                if !args.dry_run {
+                    doc_page::generate_rule_docs(docs_dir, &linter, &linter)?;
+                }
+            }
         }
     }
 
@@ -0,0 +1,148 @@
+//! Generate a compilable Rust source file containing a flat, static table
+//! of rule descriptors for editor integrations -- `# noqa` and config
+//! autocompletion shouldn't need a runtime dependency on the full registry.
+//! Mirrors rust-analyzer's `sourcegen_lints` generator.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use ruff::registry::{Linter, Rule, RuleNamespace, UpstreamCategory};
+use strum::IntoEnumIterator;
+
+/// The first line of a rule's `explanation()` that is neither blank nor a
+/// header (such as the `## What it does` header that starts every rule
+/// doc), cut after its first sentence if one ends on that line; a sentence
+/// that wraps onto the next line is cut at the line's end. Empty if the rule
+/// has no explanation.
+fn summary(rule: Rule) -> String {
+    let Some(explanation) = rule.explanation() else {
+        return String::new();
+    };
+
+    let Some(body) = explanation
+        .lines()
+        .find(|line| {
+            let trimmed = line.trim();
+            !trimmed.is_empty() && !trimmed.starts_with('#')
+        })
+    else {
+        return String::new();
+    };
+
+    let sentence = match body.split_once(". ") {
+        Some((first, _)) => format!("{first}."),
+        None => body.trim().to_string(),
+    };
+
+    sentence.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Write a flat `RuleInfo` table (plus `RuleGroup` descriptors keyed by
+/// `Linter` and `UpstreamCategory`) to `out_path`.
+pub(crate) fn generate_completions(out_path: &Path) -> Result<()> {
+    let mut rule_infos = String::new();
+    let mut group_infos = String::new();
+
+    for linter in Linter::iter() {
+        let mut rule_count = 0;
+
+        let mut push_rule = |rule: Rule, linter: &Linter| {
+            rule_infos.push_str(&format!(
+                "    RuleInfo {{ code: \"{}{}\", name: \"{}\", summary: \"{}\" }},\n",
+                linter.common_prefix(),
+                linter.code_for_rule(&rule).unwrap(),
+                rule.as_ref(),
+                summary(rule)
+            ));
+        };
+
+        if let Some(categories) = linter.upstream_categories() {
+            for UpstreamCategory(prefix, _name) in categories {
+                let mut category_count = 0;
+                for rule in prefix {
+                    push_rule(rule, &linter);
+                    category_count += 1;
+                }
+                rule_count += category_count;
+                group_infos.push_str(&format!(
+                    "    RuleGroup {{ linter: \"{}\", prefix: \"{}{}\", rule_count: {} }},\n",
+                    linter.name(),
+                    linter.common_prefix(),
+                    prefix.short_code(),
+                    category_count
+                ));
+            }
+        } else {
+            for rule in &linter {
+                push_rule(rule, &linter);
+                rule_count += 1;
+            }
+        }
+
+        group_infos.push_str(&format!(
+            "    RuleGroup {{ linter: \"{}\", prefix: \"{}\", rule_count: {} }},\n",
+            linter.name(),
+            linter.common_prefix(),
+            rule_count
+        ));
+    }
+
+    let source = format!(
+        "//! @generated by `cargo dev generate-rules-table --completions-out`.\n\
+         //! Do not edit by hand.\n\n\
+         #[derive(Debug, Clone, Copy)]\n\
+         pub struct RuleInfo {{\n    \
+             pub code: &'static str,\n    \
+             pub name: &'static str,\n    \
+             pub summary: &'static str,\n\
+         }}\n\n\
+         #[derive(Debug, Clone, Copy)]\n\
+         pub struct RuleGroup {{\n    \
+             pub linter: &'static str,\n    \
+             pub prefix: &'static str,\n    \
+             pub rule_count: usize,\n\
+         }}\n\n\
+         pub static RULES: &[RuleInfo] = &[\n{rule_infos}];\n\n\
+         pub static RULE_GROUPS: &[RuleGroup] = &[\n{group_infos}];\n"
+    );
+
+    if let Some(parent) = out_path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    fs::write(out_path, source)
+        .with_context(|| format!("failed to write {}", out_path.display()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use strum::IntoEnumIterator;
+
+    use ruff::registry::Rule;
+
+    use super::summary;
+
+    #[test]
+    fn summaries_skip_the_what_it_does_header() {
+        for rule in Rule::iter() {
+            assert_ne!(summary(rule), "What it does");
+        }
+    }
+
+    #[test]
+    fn summaries_differ_across_rules() {
+        let mut seen = HashSet::new();
+        for rule in Rule::iter() {
+            let text = summary(rule);
+            if text.is_empty() {
+                continue;
+            }
+            assert!(seen.insert(text.clone()), "duplicate summary: {text}");
+        }
+    }
+}
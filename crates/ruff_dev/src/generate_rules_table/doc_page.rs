@@ -0,0 +1,111 @@
+//! Render a standalone Markdown page for a single rule.
+//!
+//! This lives alongside the README table generation in
+//! `generate_rules_table.rs` so the website can deep-link to
+//! `docs/rules/<rule-name>.md` instead of a page this crate never wrote.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use ruff::registry::{Linter, Rule, RuleFixMeta, RuleNamespace};
+
+use super::produces;
+
+/// Write one Markdown page per rule in `rules` to `docs_dir/<rule-name>.md`.
+/// Rules without an `explanation()` get no page.
+pub(crate) fn generate_rule_docs(
+    docs_dir: &Path,
+    rules: impl IntoIterator<Item = Rule>,
+    linter: &Linter,
+) -> Result<()> {
+    fs::create_dir_all(docs_dir)?;
+
+    for rule in rules {
+        let Some(explanation) = rule.explanation() else {
+            continue;
+        };
+
+        let rule_name = rule.as_ref();
+        let code = format!(
+            "{}{}",
+            linter.common_prefix(),
+            linter.code_for_rule(&rule).unwrap()
+        );
+
+        let rendered_explanation = produces::render(rule, explanation.trim());
+
+        let mut page = String::new();
+        page.push_str(&format!("# {rule_name} ({code})\n\n"));
+        page.push_str(&rendered_explanation);
+        page.push_str("\n\n");
+
+        page.push_str("## Messages\n\n");
+        for message in rule.message_formats() {
+            page.push_str(&format!("- `{message}`\n"));
+        }
+        page.push('\n');
+
+        page.push_str("## Fix availability\n\n");
+        page.push_str(match rule.fix_meta() {
+            RuleFixMeta::None => "This rule does not have an automatic fix.\n",
+            RuleFixMeta::Conditional => {
+                "This rule has an automatic fix available under certain conditions.\n"
+            }
+            RuleFixMeta::SafeFix => {
+                "This rule has a safe automatic fix, applied with `--fix`.\n"
+            }
+            RuleFixMeta::UnsafeFix => {
+                "This rule has an unsafe automatic fix, applied with `--fix --unsafe-fixes`.\n"
+            }
+        });
+        page.push('\n');
+
+        if let Some(url) = linter.url() {
+            page.push_str(&format!("Derived from [{}]({url}).\n", linter.name()));
+        }
+
+        fs::write(docs_dir.join(format!("{rule_name}.md")), page)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use ruff::registry::{Linter, Rule, RuleNamespace, UpstreamCategory};
+
+    use super::generate_rule_docs;
+
+    #[test]
+    fn writes_a_page_per_rule_with_an_explanation() {
+        let dir = tempfile::tempdir().unwrap();
+
+        for linter in Linter::iter() {
+            if let Some(categories) = linter.upstream_categories() {
+                for UpstreamCategory(prefix, _name) in categories {
+                    generate_rule_docs(dir.path(), prefix, &linter).unwrap();
+                }
+            } else {
+                generate_rule_docs(dir.path(), &linter, &linter).unwrap();
+            }
+        }
+
+        let mut checked_any = false;
+        for rule in Rule::iter() {
+            if rule.explanation().is_none() {
+                continue;
+            }
+            checked_any = true;
+
+            let page =
+                std::fs::read_to_string(dir.path().join(format!("{}.md", rule.as_ref())))
+                    .unwrap();
+            assert!(page.starts_with(&format!("# {}", rule.as_ref())));
+            assert!(page.contains("## Fix availability"));
+        }
+        assert!(checked_any, "expected at least one rule with an explanation");
+    }
+}
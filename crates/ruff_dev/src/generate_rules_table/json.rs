@@ -0,0 +1,106 @@
+//! Serialize the full rule catalog as stable, machine-readable JSON, so
+//! editors, docs sites, and other downstream tooling can consume it without
+//! scraping the README table.
+
+use anyhow::Result;
+use itertools::Itertools;
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+use ruff::registry::{Linter, Rule, RuleFixMeta, RuleNamespace, UpstreamCategory};
+
+#[derive(Serialize)]
+struct RuleMetadata {
+    code: String,
+    name: String,
+    message_formats: Vec<String>,
+    fix: RuleFixMeta,
+    has_explanation: bool,
+}
+
+#[derive(Serialize)]
+struct LinterMetadata {
+    name: String,
+    common_prefix: String,
+    upstream_categories: Vec<String>,
+    rules: Vec<RuleMetadata>,
+}
+
+fn rule_metadata(rule: Rule, linter: &Linter) -> RuleMetadata {
+    RuleMetadata {
+        code: format!(
+            "{}{}",
+            linter.common_prefix(),
+            linter.code_for_rule(&rule).unwrap()
+        ),
+        name: rule.as_ref().to_string(),
+        message_formats: rule.message_formats().iter().map(|m| m.to_string()).collect(),
+        fix: rule.fix_meta(),
+        has_explanation: rule.explanation().is_some(),
+    }
+}
+
+/// Serialize every `Rule` across all `Linter::iter()` entries to a stable
+/// JSON document.
+pub(crate) fn generate_json() -> Result<String> {
+    let mut linters = Vec::new();
+
+    for linter in Linter::iter() {
+        let mut rules = Vec::new();
+
+        if let Some(categories) = linter.upstream_categories() {
+            for UpstreamCategory(prefix, _name) in categories {
+                for rule in prefix {
+                    rules.push(rule_metadata(rule, &linter));
+                }
+            }
+        } else {
+            for rule in &linter {
+                rules.push(rule_metadata(rule, &linter));
+            }
+        }
+
+        let upstream_categories = linter
+            .upstream_categories()
+            .map(|categories| {
+                categories
+                    .iter()
+                    .map(|UpstreamCategory(_, name)| (*name).to_string())
+                    .collect_vec()
+            })
+            .unwrap_or_default();
+
+        linters.push(LinterMetadata {
+            name: linter.name().to_string(),
+            common_prefix: linter.common_prefix().to_string(),
+            upstream_categories,
+            rules,
+        });
+    }
+
+    Ok(serde_json::to_string_pretty(&linters)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate_json;
+
+    #[test]
+    fn produces_valid_json_with_the_documented_fields() {
+        let json = generate_json().unwrap();
+        let linters: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let linters = linters.as_array().unwrap();
+        assert!(!linters.is_empty());
+
+        let rule = linters
+            .iter()
+            .find_map(|linter| linter["rules"].as_array().filter(|rules| !rules.is_empty()))
+            .expect("expected at least one linter with rules")
+            .first()
+            .unwrap();
+
+        for field in ["code", "name", "message_formats", "fix", "has_explanation"] {
+            assert!(rule.get(field).is_some(), "missing field `{field}`");
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! Substitute `{{produces}}` markers in rule documentation with the actual
+//! diagnostics ruff emits for the rule's own example.
+//!
+//! Explanations tag their "bad" example with ` ```python bad ` so we lint
+//! the right snippet even when a "good"/fixed example is shown alongside
+//! it. [`render`] runs during `cargo dev generate-rules-table` and is
+//! non-fatal: a rule whose example doesn't produce a diagnostic has its
+//! marker line replaced with an empty line rather than aborting generation. [`validate`] reruns
+//! the same extraction under `cargo test` and fails loudly on drift, so a
+//! broken example is caught in CI without ever blocking a doc build.
+
+use anyhow::{bail, Result};
+use ruff::linter::lint_only;
+use ruff::registry::{Diagnostic, Rule};
+use ruff::settings::Settings;
+
+/// The marker substituted with the rendered diagnostics for a rule's example.
+const PRODUCES_MARKER: &str = "{{produces}}";
+
+/// Extract the fenced ` ```python bad ` block in `explanation` -- the
+/// snippet explicitly tagged as the rule's "bad" example. A bare
+/// ` ```python ` or a ` ```python good ` block is assumed to already show
+/// the fixed code and is left untouched; relying on which block merely
+/// comes first would silently lint the wrong snippet if a rule's doc ever
+/// leads with its "good" example. `ignore`-tagged blocks are likewise
+/// skipped and copied verbatim.
+fn extract_example(explanation: &str) -> Option<String> {
+    let lines: Vec<&str> = explanation.lines().collect();
+    let mut i = 0;
+    while i < lines.len() {
+        let fence = lines[i].trim();
+        if let Some(tag) = fence.strip_prefix("```python") {
+            if tag.trim() == "bad" {
+                let mut body = Vec::new();
+                let mut j = i + 1;
+                while j < lines.len() && lines[j].trim() != "```" {
+                    body.push(lines[j]);
+                    j += 1;
+                }
+                return Some(body.join("\n"));
+            }
+            // Any other ```python block (untagged, "good", or "ignore")
+            // isn't the one we lint; skip past it untouched.
+            while i < lines.len() && lines[i].trim() != "```" {
+                i += 1;
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Lint `source` with only `rule` enabled. This is the single call site
+/// that needs updating if `linter::lint_only`'s signature changes shape.
+fn lint_with_only(rule: Rule, source: &str) -> Vec<Diagnostic> {
+    lint_only(source, rule, &Settings::default())
+}
+
+fn render_diagnostics(rule: Rule, source: &str) -> String {
+    let diagnostics = lint_with_only(rule, source);
+    if diagnostics.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::from("```text\n");
+    for diagnostic in &diagnostics {
+        out.push_str(&diagnostic.to_string());
+        out.push('\n');
+    }
+    out.push_str("```");
+    out
+}
+
+/// Replace a literal `{{produces}}` line in `explanation` with the rendered
+/// diagnostics for the rule's own example. Leaves `explanation` untouched if
+/// there's no marker, or no example to lint.
+pub(crate) fn render(rule: Rule, explanation: &str) -> String {
+    if !explanation.contains(PRODUCES_MARKER) {
+        return explanation.to_string();
+    }
+
+    let Some(example) = extract_example(explanation) else {
+        return explanation.to_string();
+    };
+
+    let rendered = render_diagnostics(rule, &example);
+    explanation
+        .lines()
+        .map(|line| {
+            if line.trim() == PRODUCES_MARKER {
+                rendered.as_str()
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Run under `cargo test`: fail if a rule's example produces no diagnostic,
+/// any emitted diagnostic belongs to a different rule, or a `{{produces}}`
+/// marker is left unfilled.
+///
+/// `code` is only used to label failures; which rule actually fired is
+/// checked by comparing `Rule` values directly (via [`lint_with_only`]),
+/// not by re-deriving or parsing a formatted code string.
+pub(crate) fn validate(rule: Rule, code: &str, explanation: &str) -> Result<()> {
+    if !explanation.contains(PRODUCES_MARKER) {
+        return Ok(());
+    }
+
+    let Some(example) = extract_example(explanation) else {
+        bail!("{code}: `{{{{produces}}}}` marker with no ```python bad example to lint");
+    };
+
+    let diagnostics = lint_with_only(rule, &example);
+    if diagnostics.is_empty() {
+        bail!("{code}: example produces no diagnostics");
+    }
+    if diagnostics.iter().any(|diagnostic| diagnostic.rule() != rule) {
+        bail!("{code}: example produces a diagnostic for a different rule");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use ruff::registry::{Linter, Rule, RuleNamespace};
+
+    use super::{extract_example, validate};
+
+    #[test]
+    fn extract_example_picks_the_bad_tagged_block() {
+        let explanation = "intro\n\
+             ```python good\n\
+             import os\n\
+             os.path.join(\"a\", \"b\")\n\
+             ```\n\
+             ```python bad\n\
+             import os\n\
+             ```\n";
+
+        assert_eq!(extract_example(explanation).as_deref(), Some("import os"));
+    }
+
+    #[test]
+    fn extract_example_skips_ignore_tagged_blocks() {
+        let explanation = "intro\n\
+             ```python ignore\n\
+             # not this one\n\
+             ```\n\
+             ```python bad\n\
+             import os\n\
+             ```\n";
+
+        assert_eq!(extract_example(explanation).as_deref(), Some("import os"));
+    }
+
+    #[test]
+    fn extract_example_ignores_an_untagged_block() {
+        let explanation = "intro\n\
+             ```python\n\
+             import os\n\
+             ```\n";
+
+        assert_eq!(extract_example(explanation), None);
+    }
+
+    #[test]
+    fn extract_example_returns_none_without_a_python_block() {
+        assert_eq!(extract_example("no code blocks here"), None);
+    }
+
+    #[test]
+    fn produces_markers_match_diagnostics() {
+        for rule in Rule::iter() {
+            let Some(explanation) = rule.explanation() else {
+                continue;
+            };
+            let code = Linter::iter()
+                .find_map(|linter| linter.code_for_rule(&rule).map(|c| format!("{}{c}", linter.common_prefix())))
+                .unwrap();
+            validate(rule, &code, explanation).unwrap();
+        }
+    }
+}
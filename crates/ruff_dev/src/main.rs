@@ -0,0 +1,34 @@
+//! `cargo dev`: developer-only utilities for maintaining this repository
+//! that aren't useful to end users of `ruff` itself.
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+mod generate_rules_table;
+mod new_rule;
+mod utils;
+
+#[derive(Parser)]
+#[command(name = "cargo dev")]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate the `README.md` rules table (and optional docs/JSON/
+    /// completions outputs).
+    GenerateRulesTable(generate_rules_table::Args),
+    /// Scaffold a new rule: implementation stub, fixture, registry entry,
+    /// and a refreshed rules table.
+    NewRule(new_rule::Args),
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    match args.command {
+        Command::GenerateRulesTable(args) => generate_rules_table::main(&args),
+        Command::NewRule(args) => new_rule::main(&args),
+    }
+}
@@ -0,0 +1,169 @@
+//! Generate boilerplate for a new lint rule.
+//!
+//! Mirrors clippy's `clippy_dev new_lint`: create the rule implementation,
+//! register it in the registry, create an empty snapshot-test fixture, and
+//! regenerate the README table in the same pass -- removing the
+//! error-prone manual checklist of touching the registry, the fixtures,
+//! and the generated table by hand whenever a contributor adds a rule.
+#![allow(clippy::print_stdout, clippy::print_stderr)]
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use ruff::registry::Linter;
+
+use crate::generate_rules_table;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// The linter (e.g. `Pyflakes`, `Pycodestyle`) the new rule belongs to.
+    #[arg(long)]
+    pub(crate) linter: Linter,
+    /// The rule name, in `PascalCase` (e.g. `UnusedImport`).
+    pub(crate) name: String,
+    /// The rule's code within the linter's prefix (e.g. `401` for `F401`).
+    pub(crate) code: String,
+}
+
+fn snake_case(name: &str) -> String {
+    name.chars()
+        .enumerate()
+        .flat_map(|(i, c)| {
+            if c.is_uppercase() && i > 0 {
+                vec!['_', c.to_ascii_lowercase()]
+            } else {
+                vec![c.to_ascii_lowercase()]
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::snake_case;
+
+    #[test]
+    fn converts_pascal_case_rule_names() {
+        assert_eq!(snake_case("UnusedImport"), "unused_import");
+        assert_eq!(snake_case("ABC"), "a_b_c");
+        assert_eq!(snake_case("fstring"), "fstring");
+    }
+}
+
+/// The registry source file. `register_rule` inserts a new entry above the
+/// linter's `// {linter}_RULES_END` marker in it, and fails if that marker
+/// is missing.
+const REGISTRY_PATH: &str = "crates/ruff/src/registry.rs";
+
+/// Insert `code => <module path>::name,` into the registry's rule map for
+/// `linter`, where `module` is the same `rules/<linter>/rules`-style path
+/// the rule stub was written under.
+///
+/// Returns an error (rather than silently doing nothing) if the registry
+/// file, or this linter's insertion marker, can't be found -- a stale
+/// marker convention shouldn't look like a successful registration.
+fn register_rule(linter: &Linter, module: &str, code: &str, name: &str) -> Result<()> {
+    let registry_path = PathBuf::from(REGISTRY_PATH);
+    let registry = fs::read_to_string(&registry_path)
+        .with_context(|| format!("failed to read {}", registry_path.display()))?;
+
+    let marker = format!("// {}_RULES_END", linter.name().to_uppercase().replace(' ', "_"));
+    let Some(marker_pos) = registry.find(&marker) else {
+        bail!(
+            "could not find `{marker}` in {}; register `{name}` as `{code}` under `Linter::{}` \
+             by hand, then rerun `cargo dev generate-rules-table`",
+            registry_path.display(),
+            linter.name()
+        );
+    };
+
+    let rule_module = module.replace('/', "::");
+    let insertion = format!("    \"{code}\" => {rule_module}::{name},\n");
+    let mut updated = registry.clone();
+    updated.insert_str(marker_pos, &insertion);
+
+    fs::write(&registry_path, updated)
+        .with_context(|| format!("failed to write {}", registry_path.display()))
+}
+
+pub fn main(args: &Args) -> Result<()> {
+    let module = format!(
+        "rules/{}/rules",
+        args.linter.name().to_lowercase().replace(' ', "_")
+    );
+    let rules_dir = PathBuf::from("crates/ruff/src").join(&module);
+    if !rules_dir.is_dir() {
+        bail!(
+            "expected an existing rules directory at {}; is `--linter` correct?",
+            rules_dir.display()
+        );
+    }
+
+    let file_stem = snake_case(&args.name);
+    let rule_path = rules_dir.join(format!("{file_stem}.rs"));
+    if rule_path.exists() {
+        bail!("{} already exists", rule_path.display());
+    }
+
+    let name = &args.name;
+    fs::write(
+        &rule_path,
+        format!(
+            "use ruff_macros::{{derive_message_formats, violation}};\n\n\
+             use crate::violation::Violation;\n\n\
+             /// ## What it does\n\
+             /// TODO: document what this rule checks for.\n\
+             ///\n\
+             /// ## Why is this bad?\n\
+             /// TODO: explain why this is a problem.\n\
+             ///\n\
+             /// ## Example\n\
+             /// ```python bad\n\
+             /// # TODO: add an example that triggers the rule.\n\
+             /// ```\n\
+             /// {{{{produces}}}}\n\
+             #[violation]\n\
+             pub struct {name};\n\n\
+             impl Violation for {name} {{\n    \
+                 #[derive_message_formats]\n    \
+                 fn message(&self) -> String {{\n        \
+                     format!(\"TODO: write the {name} message\")\n    \
+                 }}\n\
+             }}\n"
+        ),
+    )
+    .with_context(|| format!("failed to write {}", rule_path.display()))?;
+
+    let fixtures_dir = PathBuf::from("crates/ruff/resources/test/fixtures")
+        .join(args.linter.name().to_lowercase().replace(' ', "_"));
+    fs::create_dir_all(&fixtures_dir)
+        .with_context(|| format!("failed to create {}", fixtures_dir.display()))?;
+    let fixture_path = fixtures_dir.join(format!("{file_stem}.py"));
+    fs::write(&fixture_path, "# TODO: add a snippet that triggers the rule.\n")
+        .with_context(|| format!("failed to write {}", fixture_path.display()))?;
+
+    register_rule(&args.linter, &module, &args.code, name)?;
+
+    println!("Created {}", rule_path.display());
+    println!("Created {}", fixture_path.display());
+    println!(
+        "Registered `{name}` as `{}{}` in {REGISTRY_PATH}.",
+        args.linter.common_prefix(),
+        args.code
+    );
+    println!(
+        "Next: implement the check, then add a snapshot test pointing at {}.",
+        fixture_path.display()
+    );
+
+    // This regenerates the table from the rules compiled into this binary,
+    // which do not include the rule just registered; it shows up in the
+    // table only after a rebuild and another `cargo dev generate-rules-table`.
+    generate_rules_table::main(&generate_rules_table::Args {
+        dry_run: false,
+        docs_dir: None,
+        format: generate_rules_table::Format::Readme,
+        completions_out: None,
+    })
+}